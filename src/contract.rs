@@ -1,16 +1,24 @@
 #[cfg(not(feature = "library"))]
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{State, STATE};
+use crate::msg::{
+    ApprovalResponse, ExecuteMsg, FundersResponse, InstantiateMsg, OperatorsResponse,
+    PriceResponse, QueryMsg,
+};
+use crate::state::{
+    ContractStatus, Expiration, State, APPROVALS, FUNDERS, OPERATORS, STATE, STATUS,
+};
 use cosmwasm_std::{
-    entry_point, to_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response,
-    StdResult,
+    entry_point, to_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Storage, Uint128,
 };
 use cw2::set_contract_version;
 
 const CONTRACT_NAME: &str = "crates.io:cosmwasm-option";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Fixed-point scale used to report `Price` with sub-unit precision.
+const PRICE_PRECISION: u128 = 1_000_000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -18,24 +26,51 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
-    if msg.expires <= env.block.height {
+    if let Expiration::Never {} = msg.expires {
         return Err(ContractError::CustomError {
-            val: format!(
-                "Option expired, expires: {:?}, block height: {:?}",
-                msg.expires, env.block.height
-            ),
+            val: "Option must expire, expires: Never is not allowed".to_string(),
+        });
+    }
+    if msg.expires.is_expired(&env) {
+        return Err(ContractError::CustomError {
+            val: format!("Option expired, expires: {:?}", msg.expires),
+        });
+    }
+
+    let admin = msg
+        .admin
+        .map(|admin| deps.api.addr_validate(&admin))
+        .transpose()?;
+
+    let collateral = normalize_coins(info.funds);
+    let counter_offer = normalize_coins(msg.counter_offer);
+    let premium = normalize_coins(msg.premium);
+    validate_denoms(&collateral, &msg.accepted_denoms)?;
+    validate_denoms(&counter_offer, &msg.accepted_denoms)?;
+    // Syndicated funding weighs each funder's contribution by raw amount, which
+    // only means anything when everyone is contributing the same fungible
+    // denom, so a multi-denom counter_offer isn't supported.
+    if counter_offer.len() > 1 {
+        return Err(ContractError::CustomError {
+            val: "counter_offer must use a single denom for syndicated funding".to_string(),
         });
     }
 
     let state = State {
         creator: info.sender.clone(),
         owner: info.sender.clone(),
-        collateral: info.funds,
-        counter_offer: msg.counter_offer,
+        collateral,
+        counter_offer,
         expires: msg.expires,
+        admin,
+        total_funded: vec![],
+        premium,
+        bought: false,
+        accepted_denoms: msg.accepted_denoms,
     };
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     STATE.save(deps.storage, &state)?;
+    STATUS.save(deps.storage, &ContractStatus::Normal)?;
     Ok(Response::default())
 }
 
@@ -50,73 +85,566 @@ pub fn execute(
         ExecuteMsg::Transfer { recipient } => transfer(deps, env, info, recipient),
         ExecuteMsg::Finalize => finalize(deps, env, info),
         ExecuteMsg::Burn => burn(deps, env, info),
+        ExecuteMsg::Approve { spender, expires } => approve(deps, env, info, spender, expires),
+        ExecuteMsg::Revoke { spender } => revoke(deps, info, spender),
+        ExecuteMsg::ApproveAll { operator, expires } => {
+            approve_all(deps, env, info, operator, expires)
+        }
+        ExecuteMsg::RevokeAll { operator } => revoke_all(deps, info, operator),
+        ExecuteMsg::SetContractStatus { level } => set_contract_status(deps, info, level),
+        ExecuteMsg::Fund {} => fund(deps, env, info),
+        ExecuteMsg::Refund {} => refund(deps, env, info),
+        ExecuteMsg::BuyOption {} => buy_option(deps, env, info),
+    }
+}
+
+pub fn buy_option(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let status = STATUS.load(deps.storage)?;
+    if !status.transactions_allowed() {
+        return Err(ContractError::CustomError {
+            val: "Contract status disallows transactions".to_string(),
+        });
+    }
+
+    let mut state = STATE.load(deps.storage)?;
+    // Once the creator has transferred the option away, it's no longer up for
+    // sale out from under whoever it was transferred to.
+    if state.owner != state.creator {
+        return Err(ContractError::CustomError {
+            val: "Option has already been transferred and is no longer for sale".to_string(),
+        });
+    }
+    if state.bought {
+        return Err(ContractError::CustomError {
+            val: "Option has already been bought".to_string(),
+        });
+    }
+    if state.expires.is_expired(&env) {
+        return Err(ContractError::CustomError {
+            val: format!("Option expired, expires: {:?}", state.expires),
+        });
+    }
+    let funds = normalize_coins(info.funds.clone());
+    if funds != state.premium {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "Premium mismatch, premium: {:?}, funds: {:?}",
+                state.premium, info.funds
+            ),
+        });
+    }
+
+    clear_approvals(deps.storage)?;
+    state.owner = info.sender.clone();
+    state.bought = true;
+    let premium = state.premium.clone();
+    let creator = state.creator.clone();
+    STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: creator.to_string(),
+            amount: premium,
+        })
+        .add_attribute("action", "buy_option")
+        .add_attribute("buyer", info.sender))
+}
+
+pub fn set_contract_status(
+    deps: DepsMut,
+    info: MessageInfo,
+    level: ContractStatus,
+) -> Result<Response, ContractError> {
+    let state = STATE.load(deps.storage)?;
+    if state.admin != Some(info.sender) {
+        return Err(ContractError::Unauthorized {});
     }
+    STATUS.save(deps.storage, &level)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_contract_status")
+        .add_attribute("level", format!("{:?}", level)))
 }
 
 pub fn transfer(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: Addr,
 ) -> Result<Response, ContractError> {
-    STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
-        if info.sender != state.owner {
-            return Err(ContractError::Unauthorized {});
-        }
-        state.owner = recipient.clone();
-        Ok(state)
-    })?;
+    let status = STATUS.load(deps.storage)?;
+    if !status.transactions_allowed() {
+        return Err(ContractError::CustomError {
+            val: "Contract status disallows transactions".to_string(),
+        });
+    }
+
+    let mut state = STATE.load(deps.storage)?;
+    assert_can_transfer(deps.as_ref(), &env, &info, &state)?;
+
+    clear_approvals(deps.storage)?;
+    state.owner = recipient.clone();
+    STATE.save(deps.storage, &state)?;
+
     Ok(Response::new()
         .add_attribute("action", "transfer")
         .add_attribute("owner", recipient))
 }
 
-pub fn finalize(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+/// An option can be transferred by its true owner, by a spender holding an
+/// unexpired approval, or by an unexpired operator of the owner.
+fn assert_can_transfer(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    state: &State,
+) -> Result<(), ContractError> {
+    if info.sender == state.owner {
+        return Ok(());
+    }
+    if let Some(expires) = APPROVALS.may_load(deps.storage, info.sender.clone())? {
+        if !expires.is_expired(env) {
+            return Ok(());
+        }
+    }
+    if let Some(expires) =
+        OPERATORS.may_load(deps.storage, (state.owner.clone(), info.sender.clone()))?
+    {
+        if !expires.is_expired(env) {
+            return Ok(());
+        }
+    }
+    Err(ContractError::Unauthorized {})
+}
+
+fn clear_approvals(storage: &mut dyn Storage) -> StdResult<()> {
+    let spenders: Vec<Addr> = APPROVALS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for spender in spenders {
+        APPROVALS.remove(storage, spender);
+    }
+    Ok(())
+}
+
+pub fn approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: Addr,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
     let state = STATE.load(deps.storage)?;
+    if info.sender != state.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let expires = expires.unwrap_or(Expiration::Never {});
+    if expires.is_expired(&env) {
+        return Err(ContractError::CustomError {
+            val: "Expiration is in the past".to_string(),
+        });
+    }
+    APPROVALS.save(deps.storage, spender.clone(), &expires)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve")
+        .add_attribute("spender", spender))
+}
 
+pub fn revoke(deps: DepsMut, info: MessageInfo, spender: Addr) -> Result<Response, ContractError> {
+    let state = STATE.load(deps.storage)?;
     if info.sender != state.owner {
         return Err(ContractError::Unauthorized {});
     }
-    if env.block.height >= state.expires {
+    APPROVALS.remove(deps.storage, spender.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke")
+        .add_attribute("spender", spender))
+}
+
+pub fn approve_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operator: Addr,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let expires = expires.unwrap_or(Expiration::Never {});
+    if expires.is_expired(&env) {
         return Err(ContractError::CustomError {
-            val: format!(
-                "Option expired, expires: {:?}, block height: {:?}",
-                state.expires, env.block.height
-            ),
+            val: "Expiration is in the past".to_string(),
+        });
+    }
+    OPERATORS.save(deps.storage, (info.sender, operator.clone()), &expires)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_all")
+        .add_attribute("operator", operator))
+}
+
+pub fn revoke_all(
+    deps: DepsMut,
+    info: MessageInfo,
+    operator: Addr,
+) -> Result<Response, ContractError> {
+    OPERATORS.remove(deps.storage, (info.sender, operator.clone()));
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_all")
+        .add_attribute("operator", operator))
+}
+
+pub fn fund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let status = STATUS.load(deps.storage)?;
+    if !status.transactions_allowed() {
+        return Err(ContractError::CustomError {
+            val: "Contract status disallows transactions".to_string(),
         });
     }
-    if info.funds != state.counter_offer {
+
+    let mut state = STATE.load(deps.storage)?;
+    if state.expires.is_expired(&env) {
         return Err(ContractError::CustomError {
-            val: format!(
-                "Counter offer mismatch, counter offer: {:?}, funds: {:?}",
-                state.counter_offer, info.funds
-            ),
+            val: format!("Option expired, expires: {:?}", state.expires),
         });
     }
+    if info.funds.is_empty() {
+        return Err(ContractError::CustomError {
+            val: "Must send funds to fund the option".to_string(),
+        });
+    }
+    validate_denoms(&info.funds, &state.accepted_denoms)?;
+    for coin in &info.funds {
+        let target = state
+            .counter_offer
+            .iter()
+            .find(|c| c.denom == coin.denom)
+            .ok_or_else(|| ContractError::CustomError {
+                val: format!("{} is not part of the counter offer", coin.denom),
+            })?;
+        let funded_so_far = state
+            .total_funded
+            .iter()
+            .find(|c| c.denom == coin.denom)
+            .map(|c| c.amount)
+            .unwrap_or_default();
+        let new_total = funded_so_far
+            .checked_add(coin.amount)
+            .map_err(|_| ContractError::Overflow {})?;
+        if new_total > target.amount {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "Contribution exceeds remaining target for {}: remaining {}",
+                    coin.denom,
+                    target.amount - funded_so_far
+                ),
+            });
+        }
+    }
 
-    STATE.remove(deps.storage);
+    let mut contributed = FUNDERS
+        .may_load(deps.storage, info.sender.clone())?
+        .unwrap_or_default();
+    add_funds(&mut contributed, &info.funds)?;
+    FUNDERS.save(deps.storage, info.sender.clone(), &contributed)?;
+
+    add_funds(&mut state.total_funded, &info.funds)?;
+    STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "fund")
+        .add_attribute("funder", info.sender))
+}
+
+pub fn refund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut state = STATE.load(deps.storage)?;
+    if !state.expires.is_expired(&env) {
+        return Err(ContractError::CustomError {
+            val: format!("Option not yet expired, expires: {:?}", state.expires),
+        });
+    }
+    // Once BuyOption has been called, a fully-funded option must be settled
+    // via finalize. Before that, funders are never on the hook for a sale
+    // that might not happen, so refund always stays available.
+    if state.bought && funds_meet_target(&state.total_funded, &state.counter_offer) {
+        return Err(ContractError::CustomError {
+            val: "Option was fully funded, call finalize instead".to_string(),
+        });
+    }
+
+    let refund = FUNDERS
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or_else(|| ContractError::CustomError {
+            val: "No funds to refund".to_string(),
+        })?;
+    FUNDERS.remove(deps.storage, info.sender.clone());
+
+    subtract_funds(&mut state.total_funded, &refund)?;
+    STATE.save(deps.storage, &state)?;
 
     Ok(Response::new()
         .add_message(BankMsg::Send {
-            to_address: state.creator.to_string(),
-            amount: state.counter_offer,
-        })
-        .add_message(BankMsg::Send {
-            to_address: state.owner.to_string(),
-            amount: state.collateral,
+            to_address: info.sender.to_string(),
+            amount: refund,
         })
-        .add_attribute("action", "execute"))
+        .add_attribute("action", "refund"))
+}
+
+fn add_funds(total: &mut Vec<Coin>, funds: &[Coin]) -> Result<(), ContractError> {
+    for coin in funds {
+        match total.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(existing) => {
+                existing.amount = existing
+                    .amount
+                    .checked_add(coin.amount)
+                    .map_err(|_| ContractError::Overflow {})?;
+            }
+            None => total.push(coin.clone()),
+        }
+    }
+    Ok(())
+}
+
+fn subtract_funds(total: &mut Vec<Coin>, funds: &[Coin]) -> Result<(), ContractError> {
+    for coin in funds {
+        if let Some(existing) = total.iter_mut().find(|c| c.denom == coin.denom) {
+            existing.amount = existing
+                .amount
+                .checked_sub(coin.amount)
+                .map_err(|_| ContractError::Overflow {})?;
+        }
+    }
+    Ok(())
+}
+
+fn funds_meet_target(total: &[Coin], target: &[Coin]) -> bool {
+    target.iter().all(|t| {
+        total
+            .iter()
+            .find(|c| c.denom == t.denom)
+            .map(|c| c.amount >= t.amount)
+            .unwrap_or(false)
+    })
+}
+
+/// Merges coins of the same denom and sorts by denom, so `[BTC, ETH]` and
+/// `[ETH, BTC]` (or duplicate entries) compare and display identically.
+fn normalize_coins(coins: Vec<Coin>) -> Vec<Coin> {
+    let mut normalized: Vec<Coin> = vec![];
+    for coin in coins {
+        match normalized.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(existing) => existing.amount += coin.amount,
+            None => normalized.push(coin),
+        }
+    }
+    normalized.sort_by(|a, b| a.denom.cmp(&b.denom));
+    normalized
+}
+
+/// Rejects any coin whose denom isn't in `accepted`. An empty `accepted` list
+/// means any denom is allowed.
+fn validate_denoms(coins: &[Coin], accepted: &[String]) -> Result<(), ContractError> {
+    if accepted.is_empty() {
+        return Ok(());
+    }
+    for coin in coins {
+        if !accepted.iter().any(|denom| denom == &coin.denom) {
+            return Err(ContractError::CustomError {
+                val: format!("Denom {} is not an accepted denom", coin.denom),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Confirms the contract actually holds at least `required` on-chain, guarding
+/// against accounting drift between stored state and real bank balances.
+fn verify_contract_balance(
+    deps: Deps,
+    contract: &Addr,
+    required: &[Coin],
+) -> Result<(), ContractError> {
+    let balances = deps.querier.query_all_balances(contract.to_string())?;
+    for coin in required {
+        let held = balances
+            .iter()
+            .find(|b| b.denom == coin.denom)
+            .map(|b| b.amount)
+            .unwrap_or_default();
+        if held < coin.amount {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "Contract balance insufficient for {}: holds {}, needs {}",
+                    coin.denom, held, coin.amount
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Premium per unit of collateral, scaled by `PRICE_PRECISION`. Uses checked
+/// math throughout so a pathological instantiation reports `Overflow` rather
+/// than panicking.
+fn unit_price(premium: &[Coin], collateral: &[Coin]) -> Result<Uint128, ContractError> {
+    let premium_amount = premium.first().map(|c| c.amount).unwrap_or_default();
+    let collateral_amount = collateral
+        .first()
+        .map(|c| c.amount)
+        .filter(|amount| !amount.is_zero())
+        .ok_or(ContractError::Overflow {})?;
+
+    premium_amount
+        .checked_mul(Uint128::from(PRICE_PRECISION))
+        .map_err(|_| ContractError::Overflow {})?
+        .checked_div(collateral_amount)
+        .map_err(|_| ContractError::Overflow {})
+}
+
+/// Splits `collateral` among `funders` proportionally to how much of
+/// `total_funded` each contributed, handing any per-coin rounding remainder
+/// to the largest contributor.
+fn distribute_collateral(
+    collateral: &[Coin],
+    funders: &[(Addr, Vec<Coin>)],
+) -> Result<Vec<(Addr, Vec<Coin>)>, ContractError> {
+    let weight = |funds: &[Coin]| -> Uint128 {
+        funds.iter().fold(Uint128::zero(), |acc, c| acc + c.amount)
+    };
+    let total_weight = funders
+        .iter()
+        .fold(Uint128::zero(), |acc, (_, funds)| acc + weight(funds));
+    if total_weight.is_zero() {
+        return Ok(vec![]);
+    }
+    let largest = funders
+        .iter()
+        .max_by_key(|(_, funds)| weight(funds))
+        .map(|(addr, _)| addr.clone())
+        .expect("funders is non-empty");
+
+    let mut payouts: Vec<(Addr, Vec<Coin>)> =
+        funders.iter().map(|(addr, _)| (addr.clone(), vec![])).collect();
+
+    for coin in collateral {
+        let mut distributed = Uint128::zero();
+        for (i, (_, funds)) in funders.iter().enumerate() {
+            let share = coin
+                .amount
+                .checked_mul(weight(funds))
+                .map_err(|_| ContractError::CustomError {
+                    val: "Overflow while distributing collateral".to_string(),
+                })?
+                .checked_div(total_weight)
+                .map_err(|_| ContractError::CustomError {
+                    val: "Division error while distributing collateral".to_string(),
+                })?;
+            distributed += share;
+            if !share.is_zero() {
+                payouts[i].1.push(Coin {
+                    denom: coin.denom.clone(),
+                    amount: share,
+                });
+            }
+        }
+
+        let remainder = coin.amount - distributed;
+        if !remainder.is_zero() {
+            let (_, funds) = payouts
+                .iter_mut()
+                .find(|(addr, _)| *addr == largest)
+                .expect("largest contributor is in payouts");
+            match funds.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(existing) => existing.amount += remainder,
+                None => funds.push(Coin {
+                    denom: coin.denom.clone(),
+                    amount: remainder,
+                }),
+            }
+        }
+    }
+
+    Ok(payouts)
+}
+
+/// Settlement is triggered by the option's owner (or an approved
+/// spender/operator), the same authority required by `transfer`; the
+/// collateral itself still pays out pro-rata to the funding syndicate, since
+/// that's who put it up.
+pub fn finalize(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let status = STATUS.load(deps.storage)?;
+    if !status.transactions_allowed() {
+        return Err(ContractError::CustomError {
+            val: "Contract status disallows transactions".to_string(),
+        });
+    }
+    if !info.funds.is_empty() {
+        return Err(ContractError::CustomError {
+            val: format!("Funds not empty, funds: {:?}", info.funds),
+        });
+    }
+
+    let state = STATE.load(deps.storage)?;
+    assert_can_transfer(deps.as_ref(), &env, &info, &state)?;
+    if !state.bought {
+        return Err(ContractError::CustomError {
+            val: "Option has not been bought yet".to_string(),
+        });
+    }
+    if state.expires.is_expired(&env) {
+        return Err(ContractError::CustomError {
+            val: format!("Option expired, expires: {:?}", state.expires),
+        });
+    }
+    if !funds_meet_target(&state.total_funded, &state.counter_offer) {
+        return Err(ContractError::CustomError {
+            val: "Option is not yet fully funded".to_string(),
+        });
+    }
+    verify_contract_balance(deps.as_ref(), &env.contract.address, &state.collateral)?;
+    verify_contract_balance(deps.as_ref(), &env.contract.address, &state.counter_offer)?;
+
+    let funders: Vec<(Addr, Vec<Coin>)> = FUNDERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let payouts = distribute_collateral(&state.collateral, &funders)?;
+
+    STATE.remove(deps.storage);
+    for (funder, _) in &funders {
+        FUNDERS.remove(deps.storage, funder.clone());
+    }
+
+    let mut response = Response::new().add_message(BankMsg::Send {
+        to_address: state.creator.to_string(),
+        amount: state.counter_offer,
+    });
+    for (funder, amount) in payouts {
+        if !amount.is_empty() {
+            response = response.add_message(BankMsg::Send {
+                to_address: funder.to_string(),
+                amount,
+            });
+        }
+    }
+
+    Ok(response.add_attribute("action", "execute"))
 }
 
 pub fn burn(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let status = STATUS.load(deps.storage)?;
+    if !status.withdrawals_allowed() {
+        return Err(ContractError::CustomError {
+            val: "Contract status disallows withdrawals".to_string(),
+        });
+    }
+
     let state = STATE.load(deps.storage)?;
 
-    if state.expires > env.block.height {
+    if !state.expires.is_expired(&env) {
         return Err(ContractError::CustomError {
-            val: format!(
-                "Option not yet expired, expires: {:?}, block height: {:?}",
-                state.expires, env.block.height
-            ),
+            val: format!("Option not yet expired, expires: {:?}", state.expires),
         });
     }
     if !info.funds.is_empty() {
@@ -124,6 +652,15 @@ pub fn burn(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Cont
             val: format!("Funds not empty, funds: {:?}", info.funds),
         });
     }
+    // Funders must be able to reclaim an outstanding contribution via refund,
+    // which depends on STATE surviving; don't let burn remove it out from
+    // under them.
+    if state.total_funded.iter().any(|c| !c.amount.is_zero()) {
+        return Err(ContractError::CustomError {
+            val: "Outstanding funder contributions must be refunded before burning".to_string(),
+        });
+    }
+    verify_contract_balance(deps.as_ref(), &env.contract.address, &state.collateral)?;
 
     STATE.remove(deps.storage);
 
@@ -142,6 +679,48 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             let state = STATE.load(deps.storage)?;
             to_binary(&state)
         }
+        QueryMsg::Approval { spender } => {
+            let expires = APPROVALS.may_load(deps.storage, spender.clone())?;
+            to_binary(&expires.map(|expires| ApprovalResponse { spender, expires }))
+        }
+        QueryMsg::AllOperators { owner } => {
+            let operators = OPERATORS
+                .prefix(owner)
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| {
+                    let (operator, expires) = item?;
+                    Ok(ApprovalResponse {
+                        spender: operator,
+                        expires,
+                    })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_binary(&OperatorsResponse { operators })
+        }
+        QueryMsg::Status => {
+            let status = STATUS.load(deps.storage)?;
+            to_binary(&status)
+        }
+        QueryMsg::Funders => {
+            let funders = FUNDERS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            to_binary(&FundersResponse { funders })
+        }
+        QueryMsg::Funds { funder } => {
+            let funds = FUNDERS.may_load(deps.storage, funder)?.unwrap_or_default();
+            to_binary(&funds)
+        }
+        QueryMsg::Price => {
+            let state = STATE.load(deps.storage)?;
+            let price = unit_price(&state.premium, &state.collateral)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            to_binary(&PriceResponse {
+                premium: state.premium,
+                collateral: state.collateral,
+                unit_price: price,
+            })
+        }
     }
 }
 
@@ -149,7 +728,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 mod tests {
     use super::*;
     use cosmwasm_std::testing::{mock_dependencies_with_balance, mock_env, mock_info};
-    use cosmwasm_std::{attr, coins, from_binary, CosmosMsg};
+    use cosmwasm_std::{attr, coin, coins, from_binary, Coin, CosmosMsg};
 
     #[test]
     fn test_instantiate() {
@@ -158,7 +737,10 @@ mod tests {
         let mut deps = mock_dependencies_with_balance(&[]);
         let msg = InstantiateMsg {
             counter_offer: counter_offer.clone(),
-            expires: 100_000,
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
         };
         let info = mock_info("creator", &collateral);
         let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -170,7 +752,40 @@ mod tests {
         assert_eq!(state.owner, "creator");
         assert_eq!(state.collateral, collateral);
         assert_eq!(state.counter_offer, counter_offer);
-        assert_eq!(state.expires, 100_000);
+        assert_eq!(state.expires, Expiration::AtHeight(100_000));
+    }
+
+    #[test]
+    fn test_instantiate_rejects_never_and_past_expirations() {
+        let mut deps = mock_dependencies_with_balance(&[]);
+
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::Never {},
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &coins(1, "BTC"));
+        let err = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("must expire")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::AtHeight(mock_env().block.height),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &coins(1, "BTC"));
+        let err = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("Option expired")),
+            e => panic!("unexpected error: {}", e),
+        }
     }
 
     #[test]
@@ -178,7 +793,10 @@ mod tests {
         let mut deps = mock_dependencies_with_balance(&[]);
         let msg = InstantiateMsg {
             counter_offer: coins(40, "ETH"),
-            expires: 100_000,
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
         };
         let info = mock_info("creator", &coins(1, "BTC"));
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -203,46 +821,168 @@ mod tests {
     }
 
     #[test]
-    fn test_execute() {
-        let counter_offer = coins(40, "ETH");
-        let collateral = coins(1, "BTC");
+    fn test_approve_and_transfer() {
         let mut deps = mock_dependencies_with_balance(&[]);
         let msg = InstantiateMsg {
-            counter_offer: counter_offer.clone(),
-            expires: 100_000,
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
         };
-        let info = mock_info("creator", &collateral);
+        let info = mock_info("creator", &coins(1, "BTC"));
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
-        let info = mock_info("creator", &[]);
-        transfer(deps.as_mut(), mock_env(), info, Addr::unchecked("someone")).unwrap();
-
-        let info = mock_info("creator", &counter_offer);
-        let err = finalize(deps.as_mut(), mock_env(), info).unwrap_err();
+        let info = mock_info("spender", &[]);
+        let err = approve(deps.as_mut(), mock_env(), info, Addr::unchecked("spender"), None)
+            .unwrap_err();
         match err {
             ContractError::Unauthorized {} => {}
             e => panic!("unexpected error: {}", e),
         }
 
-        let info = mock_info("someone", &counter_offer);
-        let mut env = mock_env();
-        env.block.height = 200_000;
-        let err = finalize(deps.as_mut(), env, info).unwrap_err();
+        let info = mock_info("creator", &[]);
+        approve(deps.as_mut(), mock_env(), info, Addr::unchecked("spender"), None).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Approval {
+                spender: Addr::unchecked("spender"),
+            },
+        )
+        .unwrap();
+        let approval: Option<ApprovalResponse> = from_binary(&res).unwrap();
+        assert_eq!(
+            approval,
+            Some(ApprovalResponse {
+                spender: Addr::unchecked("spender"),
+                expires: Expiration::Never {},
+            })
+        );
+
+        let info = mock_info("spender", &[]);
+        transfer(deps.as_mut(), mock_env(), info, Addr::unchecked("someone")).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Approval {
+                spender: Addr::unchecked("spender"),
+            },
+        )
+        .unwrap();
+        let approval: Option<ApprovalResponse> = from_binary(&res).unwrap();
+        assert_eq!(approval, None);
+
+        let info = mock_info("spender", &[]);
+        let err = transfer(deps.as_mut(), mock_env(), info, Addr::unchecked("anyone")).unwrap_err();
         match err {
-            ContractError::CustomError { val } => assert!(val.contains("Option expired")),
+            ContractError::Unauthorized {} => {}
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_operator() {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &coins(1, "BTC"));
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("creator", &[]);
+        approve_all(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            Addr::unchecked("operator"),
+            None,
+        )
+        .unwrap();
+
+        let info = mock_info("operator", &[]);
+        transfer(deps.as_mut(), mock_env(), info, Addr::unchecked("someone")).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AllOperators {
+                owner: Addr::unchecked("creator"),
+            },
+        )
+        .unwrap();
+        let operators: OperatorsResponse = from_binary(&res).unwrap();
+        assert_eq!(operators.operators.len(), 1);
+        assert_eq!(operators.operators[0].spender, Addr::unchecked("operator"));
+
+        let info = mock_info("creator", &[]);
+        revoke_all(deps.as_mut(), info, Addr::unchecked("operator")).unwrap();
+
+        let info = mock_info("operator", &[]);
+        let err = transfer(deps.as_mut(), mock_env(), info, Addr::unchecked("anyone")).unwrap_err();
+        match err {
+            ContractError::Unauthorized {} => {}
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_execute() {
+        let counter_offer = coins(40, "ETH");
+        let collateral = coins(40, "BTC");
+        let premium = coins(5, "USDT");
+        let mut deps = mock_dependencies_with_balance(&[coin(40, "BTC"), coin(40, "ETH")]);
+        let msg = InstantiateMsg {
+            counter_offer: counter_offer.clone(),
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: premium.clone(),
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("buyer1", &premium);
+        buy_option(deps.as_mut(), mock_env(), info).unwrap();
+
+        let info = mock_info("buyer1", &coins(30, "ETH"));
+        fund(deps.as_mut(), mock_env(), info).unwrap();
+
+        let info = mock_info("creator", &[]);
+        let err = finalize(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::Unauthorized {} => {}
             e => panic!("unexpected error: {}", e),
         }
 
-        let info = mock_info("someone", &coins(39, "ETH"));
+        let info = mock_info("buyer1", &[]);
         let err = finalize(deps.as_mut(), mock_env(), info).unwrap_err();
         match err {
-            ContractError::CustomError { val } => assert!(val.contains("Counter offer mismatch")),
+            ContractError::CustomError { val } => assert!(val.contains("not yet fully funded")),
             e => panic!("unexpected error: {}", e),
         }
 
-        let info = mock_info("someone", &counter_offer);
+        let info = mock_info("buyer2", &coins(10, "ETH"));
+        fund(deps.as_mut(), mock_env(), info).unwrap();
+
+        let info = mock_info("buyer1", &[]);
+        let mut env = mock_env();
+        env.block.height = 200_000;
+        let err = finalize(deps.as_mut(), env, info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("Option expired")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let info = mock_info("buyer1", &[]);
         let res = finalize(deps.as_mut(), mock_env(), info).unwrap();
-        assert_eq!(res.messages.len(), 2);
+        assert_eq!(res.messages.len(), 3);
         assert_eq!(
             res.messages[0].msg,
             CosmosMsg::Bank(BankMsg::Send {
@@ -253,8 +993,15 @@ mod tests {
         assert_eq!(
             res.messages[1].msg,
             CosmosMsg::Bank(BankMsg::Send {
-                to_address: "someone".to_string(),
-                amount: collateral,
+                to_address: "buyer1".to_string(),
+                amount: coins(30, "BTC"),
+            })
+        );
+        assert_eq!(
+            res.messages[2].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "buyer2".to_string(),
+                amount: coins(10, "BTC"),
             })
         );
         assert_eq!(res.attributes.len(), 1);
@@ -264,13 +1011,143 @@ mod tests {
     }
 
     #[test]
-    fn test_burn() {
+    fn test_fund_and_refund() {
+        let counter_offer = coins(40, "ETH");
+        let collateral = coins(1, "BTC");
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer,
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("buyer1", &coins(30, "ETH"));
+        fund(deps.as_mut(), mock_env(), info).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Funds {
+                funder: Addr::unchecked("buyer1"),
+            },
+        )
+        .unwrap();
+        let funds: Vec<Coin> = from_binary(&res).unwrap();
+        assert_eq!(funds, coins(30, "ETH"));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Funders).unwrap();
+        let funders: FundersResponse = from_binary(&res).unwrap();
+        assert_eq!(funders.funders, vec![Addr::unchecked("buyer1")]);
+
+        let info = mock_info("buyer1", &[]);
+        let mut env = mock_env();
+        env.block.height = 200_000;
+        let res = refund(deps.as_mut(), env, info).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "buyer1".to_string(),
+                amount: coins(30, "ETH"),
+            })
+        );
+        assert_eq!(res.attributes[0], attr("action", "refund"));
+
+        let info = mock_info("buyer1", &[]);
+        let err = refund(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("not yet expired")),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_fund_rejects_overshoot() {
         let counter_offer = coins(40, "ETH");
         let collateral = coins(1, "BTC");
         let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer,
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("buyer1", &coins(30, "ETH"));
+        fund(deps.as_mut(), mock_env(), info).unwrap();
+
+        // buyer2 tries to send more than the 10 ETH still needed.
+        let info = mock_info("buyer2", &coins(20, "ETH"));
+        let err = fund(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("exceeds remaining target")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        // A denom that isn't part of the counter offer at all is rejected too.
+        let info = mock_info("buyer2", &coins(10, "BTC"));
+        let err = fund(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("not part of the counter offer")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let info = mock_info("buyer2", &coins(10, "ETH"));
+        fund(deps.as_mut(), mock_env(), info).unwrap();
+    }
+
+    #[test]
+    fn test_refund_allowed_before_bought_even_if_fully_funded() {
+        let counter_offer = coins(40, "ETH");
+        let collateral = coins(1, "BTC");
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer,
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: coins(5, "USDT"),
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // The syndicate fully funds the counter offer before anyone buys the
+        // option.
+        let info = mock_info("buyer1", &coins(40, "ETH"));
+        fund(deps.as_mut(), mock_env(), info).unwrap();
+
+        // Without BuyOption ever being called, finalize is unreachable, so
+        // refund must still work instead of stranding the deposit.
+        let mut env = mock_env();
+        env.block.height = 200_000;
+        let info = mock_info("buyer1", &[]);
+        let res = refund(deps.as_mut(), env, info).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "buyer1".to_string(),
+                amount: coins(40, "ETH"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_burn() {
+        let counter_offer = coins(40, "ETH");
+        let collateral = coins(1, "BTC");
+        let mut deps = mock_dependencies_with_balance(&collateral);
         let msg = InstantiateMsg {
             counter_offer: counter_offer.clone(),
-            expires: 100_000,
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
         };
         let info = mock_info("creator", &collateral);
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -304,4 +1181,246 @@ mod tests {
         assert_eq!(res.attributes.len(), 1);
         assert_eq!(res.attributes[0], attr("action", "burn"));
     }
+
+    #[test]
+    fn test_burn_blocked_with_outstanding_funders() {
+        let counter_offer = coins(40, "ETH");
+        let collateral = coins(1, "BTC");
+        let mut deps = mock_dependencies_with_balance(&collateral);
+        let msg = InstantiateMsg {
+            counter_offer,
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("buyer1", &coins(10, "ETH"));
+        fund(deps.as_mut(), mock_env(), info).unwrap();
+
+        // The creator can't reclaim the collateral while buyer1's deposit is
+        // still sitting in the contract, unrefunded.
+        let mut env = mock_env();
+        env.block.height = 200_000;
+        let info = mock_info("creator", &[]);
+        let err = burn(deps.as_mut(), env.clone(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("must be refunded")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let info = mock_info("buyer1", &[]);
+        refund(deps.as_mut(), env.clone(), info).unwrap();
+
+        let info = mock_info("creator", &[]);
+        burn(deps.as_mut(), env, info).unwrap();
+    }
+
+    #[test]
+    fn test_buy_option() {
+        let collateral = coins(40, "BTC");
+        let premium = coins(5, "USDT");
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: premium.clone(),
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("buyer", &coins(4, "USDT"));
+        let err = buy_option(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("Premium mismatch")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let info = mock_info("buyer", &premium);
+        let res = buy_option(deps.as_mut(), mock_env(), info).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "creator".to_string(),
+                amount: premium.clone(),
+            })
+        );
+        assert_eq!(res.attributes[0], attr("action", "buy_option"));
+        assert_eq!(res.attributes[1], attr("buyer", "buyer"));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Config).unwrap();
+        let state: State = from_binary(&res).unwrap();
+        assert_eq!(state.owner, "buyer");
+
+        let info = mock_info("buyer", &premium);
+        let err = buy_option(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("already been bought")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Price).unwrap();
+        let price: PriceResponse = from_binary(&res).unwrap();
+        assert_eq!(price.unit_price, Uint128::new(125_000));
+    }
+
+    #[test]
+    fn test_buy_option_blocked_after_transfer() {
+        let collateral = coins(40, "BTC");
+        let premium = coins(5, "USDT");
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: premium.clone(),
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // Creator gifts the option to Alice before anyone buys it.
+        let info = mock_info("creator", &[]);
+        transfer(deps.as_mut(), mock_env(), info, Addr::unchecked("alice")).unwrap();
+
+        // Bob can no longer buy it out from under Alice.
+        let info = mock_info("bob", &premium);
+        let err = buy_option(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("already been transferred")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Config).unwrap();
+        let state: State = from_binary(&res).unwrap();
+        assert_eq!(state.owner, "alice");
+        assert!(!state.bought);
+    }
+
+    #[test]
+    fn test_accepted_denoms() {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec!["BTC".to_string(), "ETH".to_string()],
+        };
+        let info = mock_info("creator", &coins(1, "USDT"));
+        let err = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("not an accepted denom")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let msg = InstantiateMsg {
+            counter_offer: coins(10, "ETH"),
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec!["BTC".to_string(), "ETH".to_string()],
+        };
+        let info = mock_info("creator", &[coin(5, "BTC"), coin(1, "BTC")]);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Config).unwrap();
+        let state: State = from_binary(&res).unwrap();
+        assert_eq!(state.collateral, coins(6, "BTC"));
+        assert_eq!(state.counter_offer, coins(10, "ETH"));
+
+        let info = mock_info("buyer1", &coins(10, "USDT"));
+        let err = fund(deps.as_mut(), mock_env(), info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("not an accepted denom")),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_instantiate_rejects_multi_denom_counter_offer() {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer: vec![coin(10, "ETH"), coin(5, "BTC")],
+            expires: Expiration::AtHeight(100_000),
+            admin: None,
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &coins(1, "BTC"));
+        let err = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("single denom")),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_set_contract_status() {
+        let counter_offer = coins(40, "ETH");
+        let collateral = coins(1, "BTC");
+        let mut deps = mock_dependencies_with_balance(&collateral);
+        let msg = InstantiateMsg {
+            counter_offer,
+            expires: Expiration::AtHeight(100_000),
+            admin: Some("admin".to_string()),
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("creator", &[]);
+        let err = set_contract_status(deps.as_mut(), info, ContractStatus::StopAll).unwrap_err();
+        match err {
+            ContractError::Unauthorized {} => {}
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let info = mock_info("admin", &[]);
+        set_contract_status(deps.as_mut(), info, ContractStatus::StopTransactions).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Status).unwrap();
+        let status: ContractStatus = from_binary(&res).unwrap();
+        assert_eq!(status, ContractStatus::StopTransactions);
+
+        let info = mock_info("creator", &[]);
+        let err = transfer(deps.as_mut(), mock_env(), info, Addr::unchecked("someone")).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("disallows transactions")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        // Collateral recovery is still allowed under `StopTransactions`.
+        let mut env = mock_env();
+        env.block.height = 200_000;
+        let info = mock_info("creator", &[]);
+        let res = burn(deps.as_mut(), env.clone(), info).unwrap();
+        assert_eq!(res.attributes[0], attr("action", "burn"));
+
+        // A fresh option escalated straight to `StopAll` blocks recovery too.
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: Expiration::AtHeight(100_000),
+            admin: Some("admin".to_string()),
+            premium: vec![],
+            accepted_denoms: vec![],
+        };
+        let info = mock_info("creator", &collateral);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        let info = mock_info("admin", &[]);
+        set_contract_status(deps.as_mut(), info, ContractStatus::StopAll).unwrap();
+
+        let info = mock_info("creator", &[]);
+        let err = burn(deps.as_mut(), env, info).unwrap_err();
+        match err {
+            ContractError::CustomError { val } => assert!(val.contains("disallows withdrawals")),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
 }
@@ -0,0 +1,107 @@
+use crate::state::{ContractStatus, Expiration};
+use cosmwasm_std::{Addr, Coin, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    pub counter_offer: Vec<Coin>,
+    pub expires: Expiration,
+    /// Optional admin allowed to pause the contract via `SetContractStatus`.
+    pub admin: Option<String>,
+    /// Paid up front by the first buyer via `BuyOption`.
+    pub premium: Vec<Coin>,
+    /// Denoms `collateral` and `counter_offer` are allowed to use; empty
+    /// means any denom is accepted.
+    pub accepted_denoms: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Transfer {
+        recipient: Addr,
+    },
+    Finalize,
+    Burn,
+    /// Grant `spender` a single approval to transfer the option, expiring per `expires`.
+    Approve {
+        spender: Addr,
+        expires: Option<Expiration>,
+    },
+    /// Revoke a previously granted approval for `spender`.
+    Revoke {
+        spender: Addr,
+    },
+    /// Grant `operator` the right to transfer the option until `expires`.
+    ApproveAll {
+        operator: Addr,
+        expires: Option<Expiration>,
+    },
+    /// Revoke a previously granted operator status for `operator`.
+    RevokeAll {
+        operator: Addr,
+    },
+    /// Admin-only killswitch to pause transactions or all activity.
+    SetContractStatus {
+        level: ContractStatus,
+    },
+    /// Contribute funds toward `counter_offer`; may be called repeatedly and
+    /// by any number of accounts.
+    Fund {},
+    /// Reclaim a funder's contribution once the option has expired
+    /// under-funded or without ever having been bought.
+    Refund {},
+    /// Pay the premium to become the option's owner; required before
+    /// `Finalize` can be called.
+    BuyOption {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Config,
+    /// Returns the current approval granted to `spender`, if any.
+    Approval {
+        spender: Addr,
+    },
+    /// Returns every operator grant stored for `owner` with its expiration;
+    /// expired grants are included.
+    AllOperators {
+        owner: Addr,
+    },
+    /// Returns the current contract status.
+    Status,
+    /// Returns the addresses of everyone who has funded the option so far.
+    Funders,
+    /// Returns the amount `funder` has contributed so far.
+    Funds {
+        funder: Addr,
+    },
+    /// Returns the premium priced per unit of collateral.
+    Price,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovalResponse {
+    pub spender: Addr,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperatorsResponse {
+    pub operators: Vec<ApprovalResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FundersResponse {
+    pub funders: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceResponse {
+    pub premium: Vec<Coin>,
+    pub collateral: Vec<Coin>,
+    /// Premium per unit of collateral, scaled by `PRICE_PRECISION`.
+    pub unit_price: Uint128,
+}
@@ -0,0 +1,80 @@
+use cosmwasm_std::{Addr, Coin, Env, Timestamp};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct State {
+    pub creator: Addr,
+    pub owner: Addr,
+    pub collateral: Vec<Coin>,
+    pub counter_offer: Vec<Coin>,
+    pub expires: Expiration,
+    pub admin: Option<Addr>,
+    /// Running total contributed so far by all funders, toward `counter_offer`.
+    pub total_funded: Vec<Coin>,
+    /// Paid up front by the first buyer via `BuyOption`.
+    pub premium: Vec<Coin>,
+    /// Whether `BuyOption` has been called; `finalize` requires this.
+    pub bought: bool,
+    /// Denoms `collateral` and `counter_offer` are allowed to use. Empty means
+    /// any denom is accepted.
+    pub accepted_denoms: Vec<String>,
+}
+
+pub const STATE: Item<State> = Item::new("state");
+
+/// Contributions made so far by each funder, keyed by their address.
+pub const FUNDERS: Map<Addr, Vec<Coin>> = Map::new("funders");
+
+/// Contract-status pattern borrowed from Fadroma's SNIP20: the admin can pull
+/// this lever to pause activity without being able to seize funds.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractStatus {
+    Normal,
+    StopTransactions,
+    StopAll,
+}
+
+impl ContractStatus {
+    /// `transfer`/`finalize` require this to be true.
+    pub fn transactions_allowed(&self) -> bool {
+        matches!(self, ContractStatus::Normal)
+    }
+
+    /// `burn` (collateral recovery) requires this to be true.
+    pub fn withdrawals_allowed(&self) -> bool {
+        !matches!(self, ContractStatus::StopAll)
+    }
+}
+
+pub const STATUS: Item<ContractStatus> = Item::new("status");
+
+/// Mirrors cw721's `Expiration`: an approval or operator grant can expire by
+/// block height, by wall-clock time, or never.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Expiration {
+    AtHeight(u64),
+    AtTime(Timestamp),
+    Never {},
+}
+
+impl Expiration {
+    pub fn is_expired(&self, env: &Env) -> bool {
+        match self {
+            Expiration::AtHeight(height) => env.block.height >= *height,
+            Expiration::AtTime(time) => env.block.time >= *time,
+            Expiration::Never {} => false,
+        }
+    }
+}
+
+/// Single-use approvals granting `spender` the right to transfer the option
+/// on the current owner's behalf. Cleared whenever the option changes hands.
+pub const APPROVALS: Map<Addr, Expiration> = Map::new("approvals");
+
+/// Operators may transfer on behalf of `owner` until their grant expires,
+/// keyed by `(owner, operator)` so a grant only ever applies to its owner.
+pub const OPERATORS: Map<(Addr, Addr), Expiration> = Map::new("operators");